@@ -15,7 +15,11 @@ use gfx_descriptor::{DescriptorCounts, DescriptorSet};
 use serde::Deserialize;
 #[cfg(feature = "trace")]
 use serde::Serialize;
-use std::borrow::Borrow;
+use std::{
+    borrow::Borrow,
+    num::NonZeroU32,
+    sync::{Arc, Weak},
+};
 
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
@@ -32,8 +36,11 @@ pub enum BindingType {
     WriteonlyStorageTexture = 7,
 }
 
+/// Pure-data description of a single binding slot, shared by the safe
+/// [`BindGroupLayoutDescriptor`] and its `#[repr(C)]` FFI adapter. `repr(C)`
+/// for the adapter; serde-derived for the replayer.
 #[repr(C)]
-#[derive(Clone, Debug, Hash, PartialEq)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
 #[cfg_attr(feature = "trace", derive(Serialize))]
 #[cfg_attr(feature = "replay", derive(Deserialize))]
 pub struct BindGroupLayoutEntry {
@@ -45,6 +52,9 @@ pub struct BindGroupLayoutEntry {
     pub view_dimension: wgt::TextureViewDimension,
     pub texture_component_type: wgt::TextureComponentType,
     pub storage_texture_format: wgt::TextureFormat,
+    /// If this is `Some`, the entry describes an array of `count` descriptors of
+    /// `ty`, bound to a single slot as e.g. `texture2D tex[count]` in the shader.
+    pub count: Option<NonZeroU32>,
 }
 
 #[derive(Clone, Debug)]
@@ -52,10 +62,51 @@ pub enum BindGroupLayoutEntryError {
     NoVisibility,
     UnexpectedHasDynamicOffset,
     UnexpectedMultisampled,
+    ArrayUnsupported,
+    TextureBindingArrayUnsupported(NonZeroU32),
+    StorageTextureCube(wgt::TextureViewDimension),
+    StorageTextureReadWrite(wgt::TextureFormat),
+    MultisampledDimension(wgt::TextureViewDimension),
+    SampleTypeFloatFilterableMultisampled(wgt::TextureComponentType),
+}
+
+/// Only float textures are filterable; integer textures never are.
+fn component_type_is_filterable(ty: wgt::TextureComponentType) -> bool {
+    match ty {
+        wgt::TextureComponentType::Float => true,
+        wgt::TextureComponentType::Sint | wgt::TextureComponentType::Uint => false,
+    }
 }
 
 impl BindGroupLayoutEntry {
-    pub(crate) fn validate(&self) -> Result<(), BindGroupLayoutEntryError> {
+    /// `storage_texture_rw` is the device's "adapter-specific format features"
+    /// capability, which is required to bind a read-only storage texture.
+    /// `texture_binding_array` is the device capability that must be present for
+    /// an entry to describe a descriptor array (`count.is_some()`); backends
+    /// without it reject the layout up front rather than crashing later.
+    pub(crate) fn validate(
+        &self,
+        storage_texture_rw: bool,
+        texture_binding_array: bool,
+    ) -> Result<(), BindGroupLayoutEntryError> {
+        self.validate_structural()?;
+        if self.ty == BindingType::ReadonlyStorageTexture && !storage_texture_rw {
+            return Err(BindGroupLayoutEntryError::StorageTextureReadWrite(
+                self.storage_texture_format,
+            ));
+        }
+        if let Some(count) = self.count {
+            if !texture_binding_array {
+                return Err(BindGroupLayoutEntryError::TextureBindingArrayUnsupported(
+                    count,
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Structural validation independent of device capabilities.
+    fn validate_structural(&self) -> Result<(), BindGroupLayoutEntryError> {
         if self.visibility.is_empty() {
             return Err(BindGroupLayoutEntryError::NoVisibility);
         }
@@ -75,18 +126,230 @@ impl BindGroupLayoutEntry {
                 }
             }
         }
+        match self.ty {
+            BindingType::ReadonlyStorageTexture | BindingType::WriteonlyStorageTexture => {
+                match self.view_dimension {
+                    wgt::TextureViewDimension::Cube | wgt::TextureViewDimension::CubeArray => {
+                        return Err(BindGroupLayoutEntryError::StorageTextureCube(
+                            self.view_dimension,
+                        ));
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+        if self.multisampled && self.ty == BindingType::SampledTexture {
+            if self.view_dimension != wgt::TextureViewDimension::D2 {
+                return Err(BindGroupLayoutEntryError::MultisampledDimension(
+                    self.view_dimension,
+                ));
+            }
+            if component_type_is_filterable(self.texture_component_type) {
+                return Err(BindGroupLayoutEntryError::SampleTypeFloatFilterableMultisampled(
+                    self.texture_component_type,
+                ));
+            }
+        }
+        if self.count.is_some() {
+            match self.ty {
+                BindingType::SampledTexture
+                | BindingType::ReadonlyStorageTexture
+                | BindingType::WriteonlyStorageTexture => {}
+                _ => return Err(BindGroupLayoutEntryError::ArrayUnsupported),
+            }
+        }
+        Ok(())
+    }
+
+    /// Native descriptors this entry occupies: `count` for an array, else one.
+    pub(crate) fn num_descriptors(&self) -> u32 {
+        self.count.map_or(1, |count| count.get())
+    }
+
+    /// Check that `resource` supplies exactly `count` descriptors for an array
+    /// entry, and that a non-array entry is not given a [`BindingResource::TextureViewArray`].
+    pub(crate) fn validate_resource(
+        &self,
+        resource: &BindingResource,
+    ) -> Result<(), BindGroupError> {
+        match self.count {
+            Some(count) => {
+                let actual = match resource {
+                    BindingResource::TextureViewArray(views) => views.len(),
+                    _ => return Err(BindGroupError::ExpectedBindingArray(self.binding)),
+                };
+                if actual != count.get() as usize {
+                    return Err(BindGroupError::BindingArrayLengthMismatch {
+                        binding: self.binding,
+                        expected: count.get() as usize,
+                        actual,
+                    });
+                }
+            }
+            None => {
+                if let BindingResource::TextureViewArray(_) = resource {
+                    return Err(BindGroupError::UnexpectedBindingArray(self.binding));
+                }
+            }
+        }
         Ok(())
     }
 }
 
+/// Builder for [`BindGroupLayoutEntry`], for Rust callers that use this crate
+/// directly (as opposed to through the FFI layer). It starts from a
+/// [`BindingType`] with sane defaults and only the fields relevant to that type
+/// need to be set.
+#[derive(Clone, Debug)]
+pub struct BindGroupLayoutEntryBuilder {
+    visibility: wgt::ShaderStage,
+    ty: BindingType,
+    multisampled: bool,
+    has_dynamic_offset: bool,
+    view_dimension: wgt::TextureViewDimension,
+    texture_component_type: wgt::TextureComponentType,
+    storage_texture_format: wgt::TextureFormat,
+    count: Option<NonZeroU32>,
+}
+
+impl BindGroupLayoutEntryBuilder {
+    pub fn new(ty: BindingType) -> Self {
+        BindGroupLayoutEntryBuilder {
+            visibility: wgt::ShaderStage::empty(),
+            ty,
+            multisampled: false,
+            has_dynamic_offset: false,
+            view_dimension: wgt::TextureViewDimension::D2,
+            texture_component_type: wgt::TextureComponentType::Float,
+            storage_texture_format: wgt::TextureFormat::Rgba8Unorm,
+            count: None,
+        }
+    }
+
+    pub fn visibility(mut self, visibility: wgt::ShaderStage) -> Self {
+        self.visibility = visibility;
+        self
+    }
+
+    pub fn dynamic_offset(mut self) -> Self {
+        self.has_dynamic_offset = true;
+        self
+    }
+
+    pub fn multisampled(mut self) -> Self {
+        self.multisampled = true;
+        self
+    }
+
+    pub fn view_dimension(mut self, view_dimension: wgt::TextureViewDimension) -> Self {
+        self.view_dimension = view_dimension;
+        self
+    }
+
+    pub fn texture_component_type(
+        mut self,
+        texture_component_type: wgt::TextureComponentType,
+    ) -> Self {
+        self.texture_component_type = texture_component_type;
+        self
+    }
+
+    pub fn storage_format(mut self, format: wgt::TextureFormat) -> Self {
+        self.storage_texture_format = format;
+        self
+    }
+
+    pub fn count(mut self, count: NonZeroU32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Finish the entry for the given `binding` slot. Structural validation runs
+    /// here; the device capability gates are applied by `validate` at layout
+    /// creation, where the device handle is available.
+    pub fn build(self, binding: u32) -> Result<BindGroupLayoutEntry, BindGroupLayoutEntryError> {
+        let entry = BindGroupLayoutEntry {
+            binding,
+            visibility: self.visibility,
+            ty: self.ty,
+            multisampled: self.multisampled,
+            has_dynamic_offset: self.has_dynamic_offset,
+            view_dimension: self.view_dimension,
+            texture_component_type: self.texture_component_type,
+            storage_texture_format: self.storage_texture_format,
+            count: self.count,
+        };
+        entry.validate_structural()?;
+        Ok(entry)
+    }
+}
+
+/// Collect `(binding, builder)` pairs into a [`BindEntryMap`], rejecting a
+/// duplicate `binding` with [`BindGroupLayoutError::ConflictBinding`] and a
+/// failing entry with [`BindGroupLayoutError::Entry`].
+pub fn bind_entry_map(
+    entries: impl IntoIterator<Item = (u32, BindGroupLayoutEntryBuilder)>,
+) -> Result<BindEntryMap, BindGroupLayoutError> {
+    let mut map = BindEntryMap::default();
+    for (binding, builder) in entries {
+        let entry = builder
+            .build(binding)
+            .map_err(|e| BindGroupLayoutError::Entry(binding, e))?;
+        if map.insert(binding, entry).is_some() {
+            return Err(BindGroupLayoutError::ConflictBinding(binding));
+        }
+    }
+    Ok(map)
+}
+
+/// Reconstruct a borrowed slice from a raw `*const T` + length pair. Returns an
+/// empty slice for a zero length so a null pointer is never dereferenced.
+unsafe fn make_slice<'a, T>(ptr: *const T, length: usize) -> &'a [T] {
+    if length == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(ptr, length)
+    }
+}
+
+/// Borrow a nul-terminated C string as a `&str`, or `None` if the pointer is
+/// null or the bytes are not valid UTF-8.
+unsafe fn make_label<'a>(label: *const std::os::raw::c_char) -> Option<&'a str> {
+    if label.is_null() {
+        None
+    } else {
+        std::ffi::CStr::from_ptr(label).to_str().ok()
+    }
+}
+
+/// Safe, Rust-native bind group layout descriptor: a borrowed slice and label
+/// with no raw pointers. [`RawBindGroupLayoutDescriptor::as_safe`] produces it.
+#[derive(Clone, Debug)]
+pub struct BindGroupLayoutDescriptor<'a> {
+    pub label: Option<&'a str>,
+    pub entries: &'a [BindGroupLayoutEntry],
+}
+
+/// FFI-facing `#[repr(C)]` adapter for [`BindGroupLayoutDescriptor`]. Convert to
+/// the safe form at the C boundary with [`RawBindGroupLayoutDescriptor::as_safe`].
 #[repr(C)]
 #[derive(Debug)]
-pub struct BindGroupLayoutDescriptor {
+pub struct RawBindGroupLayoutDescriptor {
     pub label: *const std::os::raw::c_char,
     pub entries: *const BindGroupLayoutEntry,
     pub entries_length: usize,
 }
 
+impl RawBindGroupLayoutDescriptor {
+    pub unsafe fn as_safe(&self) -> BindGroupLayoutDescriptor<'_> {
+        BindGroupLayoutDescriptor {
+            label: make_label(self.label),
+            entries: make_slice(self.entries, self.entries_length),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum BindGroupLayoutError {
     ConflictBinding(u32),
@@ -95,6 +358,88 @@ pub enum BindGroupLayoutError {
 
 pub(crate) type BindEntryMap = FastHashMap<u32, BindGroupLayoutEntry>;
 
+/// Order-independent key for a set of layout entries: the entries sorted by
+/// `binding`, so equal layouts hash equal regardless of insertion order.
+pub(crate) type BindEntryKey = Vec<BindGroupLayoutEntry>;
+
+pub(crate) fn bind_entry_key(entries: &BindEntryMap) -> BindEntryKey {
+    let mut key = entries.values().cloned().collect::<Vec<_>>();
+    key.sort_by_key(|e| e.binding);
+    key
+}
+
+/// Total native descriptors a layout reserves, counting each array entry as its
+/// `count`. This only computes the total; it does not adjust any
+/// `BindGroupLayout::desc_counts`.
+pub(crate) fn descriptor_count(entries: &BindEntryMap) -> u32 {
+    entries.values().map(BindGroupLayoutEntry::num_descriptors).sum()
+}
+
+/// Per-device pool that deduplicates identical bind group layouts so repeated
+/// pipeline declarations share a single `B::DescriptorSetLayout`. Layouts are
+/// held weakly and expired entries are pruned lazily on lookup.
+#[derive(Debug)]
+pub(crate) struct BindGroupLayoutPool<B: hal::Backend> {
+    entries: FastHashMap<BindEntryKey, (BindGroupLayoutId, Weak<BindGroupLayout<B>>)>,
+}
+
+impl<B: hal::Backend> Default for BindGroupLayoutPool<B> {
+    fn default() -> Self {
+        BindGroupLayoutPool {
+            entries: FastHashMap::default(),
+        }
+    }
+}
+
+impl<B: hal::Backend> BindGroupLayoutPool<B> {
+    /// Return the existing layout for `entries`, else create and register one
+    /// with `create`. On a hit the shared layout's `RefCount` is bumped and
+    /// `create` is not called, so no new `B::DescriptorSetLayout` is allocated.
+    pub(crate) fn get_or_create<F>(
+        &mut self,
+        entries: &BindEntryMap,
+        create: F,
+    ) -> (BindGroupLayoutId, RefCount)
+    where
+        F: FnOnce() -> (BindGroupLayoutId, Arc<BindGroupLayout<B>>),
+    {
+        if let Some(hit) = self.get(entries) {
+            return hit;
+        }
+        let (id, layout) = create();
+        let ref_count = layout.life_guard.ref_count.clone().unwrap();
+        self.insert(entries, id, &layout);
+        (id, ref_count)
+    }
+
+    /// Return a live layout for `entries`, bumping its `RefCount`. Prunes the
+    /// entry if its weak handle has expired.
+    fn get(&mut self, entries: &BindEntryMap) -> Option<(BindGroupLayoutId, RefCount)> {
+        let key = bind_entry_key(entries);
+        let hit = match self.entries.get(&key) {
+            Some((id, weak)) => weak
+                .upgrade()
+                .map(|layout| (*id, layout.life_guard.ref_count.clone().unwrap())),
+            None => return None,
+        };
+        if hit.is_none() {
+            self.entries.remove(&key);
+        }
+        hit
+    }
+
+    /// Register a freshly created layout so future identical requests reuse it.
+    fn insert(
+        &mut self,
+        entries: &BindEntryMap,
+        id: BindGroupLayoutId,
+        layout: &Arc<BindGroupLayout<B>>,
+    ) {
+        self.entries
+            .insert(bind_entry_key(entries), (id, Arc::downgrade(layout)));
+    }
+}
+
 #[derive(Debug)]
 pub struct BindGroupLayout<B: hal::Backend> {
     pub(crate) raw: B::DescriptorSetLayout,
@@ -105,13 +450,31 @@ pub struct BindGroupLayout<B: hal::Backend> {
     pub(crate) dynamic_count: usize,
 }
 
+/// Safe, Rust-native pipeline layout descriptor.
+#[derive(Clone, Debug)]
+pub struct PipelineLayoutDescriptor<'a> {
+    pub bind_group_layouts: &'a [BindGroupLayoutId],
+}
+
+/// FFI-facing `#[repr(C)]` adapter for [`PipelineLayoutDescriptor`].
 #[repr(C)]
 #[derive(Debug)]
-pub struct PipelineLayoutDescriptor {
+pub struct RawPipelineLayoutDescriptor {
     pub bind_group_layouts: *const BindGroupLayoutId,
     pub bind_group_layouts_length: usize,
 }
 
+impl RawPipelineLayoutDescriptor {
+    pub unsafe fn as_safe(&self) -> PipelineLayoutDescriptor<'_> {
+        PipelineLayoutDescriptor {
+            bind_group_layouts: make_slice(
+                self.bind_group_layouts,
+                self.bind_group_layouts_length,
+            ),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum PipelineLayoutError {
     TooManyGroups(usize),
@@ -122,7 +485,8 @@ pub struct PipelineLayout<B: hal::Backend> {
     pub(crate) raw: B::PipelineLayout,
     pub(crate) device_id: Stored<DeviceId>,
     pub(crate) life_guard: LifeGuard,
-    pub(crate) bind_group_layout_ids: ArrayVec<[Stored<BindGroupLayoutId>; MAX_BIND_GROUPS]>,
+    /// Shared handles keeping the deduplicated layouts alive for the pool.
+    pub(crate) bind_group_layouts: ArrayVec<[Arc<BindGroupLayout<B>>; MAX_BIND_GROUPS]>,
 }
 
 #[repr(C)]
@@ -143,6 +507,18 @@ pub enum BindingResource {
     Buffer(BufferBinding),
     Sampler(SamplerId),
     TextureView(TextureViewId),
+    TextureViewArray(Vec<TextureViewId>),
+}
+
+impl BindingResource {
+    /// Every texture view this resource references; an array yields all of them.
+    pub(crate) fn texture_views(&self) -> &[TextureViewId] {
+        match self {
+            BindingResource::TextureView(view) => std::slice::from_ref(view),
+            BindingResource::TextureViewArray(views) => views.as_slice(),
+            _ => &[],
+        }
+    }
 }
 
 #[repr(C)]
@@ -154,15 +530,64 @@ pub struct BindGroupEntry {
     pub resource: BindingResource,
 }
 
+/// Safe, Rust-native bind group descriptor.
+#[derive(Debug)]
+pub struct BindGroupDescriptor<'a> {
+    pub label: Option<&'a str>,
+    pub layout: BindGroupLayoutId,
+    pub entries: &'a [BindGroupEntry],
+}
+
+/// FFI-facing `#[repr(C)]` adapter for [`BindGroupDescriptor`].
 #[repr(C)]
 #[derive(Debug)]
-pub struct BindGroupDescriptor {
+pub struct RawBindGroupDescriptor {
     pub label: *const std::os::raw::c_char,
     pub layout: BindGroupLayoutId,
     pub entries: *const BindGroupEntry,
     pub entries_length: usize,
 }
 
+impl RawBindGroupDescriptor {
+    pub unsafe fn as_safe(&self) -> BindGroupDescriptor<'_> {
+        BindGroupDescriptor {
+            label: make_label(self.label),
+            layout: self.layout,
+            entries: make_slice(self.entries, self.entries_length),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum BindGroupError {
+    MissingBinding(u32),
+    ExpectedBindingArray(u32),
+    UnexpectedBindingArray(u32),
+    BindingArrayLengthMismatch {
+        binding: u32,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+/// Validate every `entry` against `layout` and return the texture views to
+/// record in the bind group's tracker, so `create_bind_group` can populate
+/// `BindGroup::used` from an array binding's full view set.
+pub(crate) fn validate_bind_group(
+    layout: &BindEntryMap,
+    entries: &[BindGroupEntry],
+) -> Result<Vec<TextureViewId>, BindGroupError> {
+    let mut views = Vec::new();
+    for entry in entries {
+        let layout_entry = layout
+            .get(&entry.binding)
+            .ok_or(BindGroupError::MissingBinding(entry.binding))?;
+        layout_entry.validate_resource(&entry.resource)?;
+        views.extend_from_slice(entry.resource.texture_views());
+    }
+    Ok(views)
+}
+
 #[derive(Debug)]
 pub struct BindGroup<B: hal::Backend> {
     pub(crate) raw: DescriptorSet<B>,
@@ -184,3 +609,141 @@ impl<B: hal::Backend> Borrow<()> for BindGroup<B> {
         &DUMMY_SELECTOR
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(ty: BindingType) -> BindGroupLayoutEntry {
+        BindGroupLayoutEntry {
+            binding: 0,
+            visibility: wgt::ShaderStage::FRAGMENT,
+            ty,
+            multisampled: false,
+            has_dynamic_offset: false,
+            view_dimension: wgt::TextureViewDimension::D2,
+            texture_component_type: wgt::TextureComponentType::Float,
+            storage_texture_format: wgt::TextureFormat::Rgba8Unorm,
+            count: None,
+        }
+    }
+
+    #[test]
+    fn num_descriptors_counts_array() {
+        assert_eq!(entry(BindingType::SampledTexture).num_descriptors(), 1);
+        let mut e = entry(BindingType::SampledTexture);
+        e.count = NonZeroU32::new(4);
+        assert_eq!(e.num_descriptors(), 4);
+    }
+
+    #[test]
+    fn descriptor_count_sums_entries() {
+        let mut map = BindEntryMap::default();
+        let mut array = entry(BindingType::SampledTexture);
+        array.count = NonZeroU32::new(4);
+        map.insert(0, array);
+        map.insert(1, entry(BindingType::Sampler));
+        assert_eq!(descriptor_count(&map), 5);
+    }
+
+    #[test]
+    fn count_rejected_for_non_texture() {
+        let mut e = entry(BindingType::UniformBuffer);
+        e.count = NonZeroU32::new(2);
+        assert!(matches!(
+            e.validate(true, true),
+            Err(BindGroupLayoutEntryError::ArrayUnsupported)
+        ));
+    }
+
+    #[test]
+    fn count_gated_by_capability() {
+        let mut e = entry(BindingType::SampledTexture);
+        e.count = NonZeroU32::new(2);
+        assert!(matches!(
+            e.validate(true, false),
+            Err(BindGroupLayoutEntryError::TextureBindingArrayUnsupported(_))
+        ));
+        assert!(e.validate(true, true).is_ok());
+    }
+
+    #[test]
+    fn storage_texture_cube_rejected() {
+        let mut e = entry(BindingType::WriteonlyStorageTexture);
+        e.view_dimension = wgt::TextureViewDimension::Cube;
+        assert!(matches!(
+            e.validate(true, true),
+            Err(BindGroupLayoutEntryError::StorageTextureCube(_))
+        ));
+    }
+
+    #[test]
+    fn readonly_storage_texture_gated() {
+        let e = entry(BindingType::ReadonlyStorageTexture);
+        assert!(matches!(
+            e.validate(false, true),
+            Err(BindGroupLayoutEntryError::StorageTextureReadWrite(_))
+        ));
+        assert!(e.validate(true, true).is_ok());
+    }
+
+    #[test]
+    fn multisampled_requires_d2() {
+        let mut e = entry(BindingType::SampledTexture);
+        e.multisampled = true;
+        e.view_dimension = wgt::TextureViewDimension::D2Array;
+        e.texture_component_type = wgt::TextureComponentType::Uint;
+        assert!(matches!(
+            e.validate(true, true),
+            Err(BindGroupLayoutEntryError::MultisampledDimension(_))
+        ));
+    }
+
+    #[test]
+    fn multisampled_float_is_filterable_rejected() {
+        let mut e = entry(BindingType::SampledTexture);
+        e.multisampled = true;
+        assert!(matches!(
+            e.validate(true, true),
+            Err(BindGroupLayoutEntryError::SampleTypeFloatFilterableMultisampled(_))
+        ));
+        e.texture_component_type = wgt::TextureComponentType::Uint;
+        assert!(e.validate(true, true).is_ok());
+    }
+
+    #[test]
+    fn builder_builds_multisampled_sampled_texture() {
+        let entry = BindGroupLayoutEntryBuilder::new(BindingType::SampledTexture)
+            .visibility(wgt::ShaderStage::FRAGMENT)
+            .multisampled()
+            .texture_component_type(wgt::TextureComponentType::Uint)
+            .build(0)
+            .unwrap();
+        assert!(entry.multisampled);
+    }
+
+    #[test]
+    fn bind_entry_map_rejects_duplicate_binding() {
+        let builders = vec![
+            (0, BindGroupLayoutEntryBuilder::new(BindingType::Sampler)
+                .visibility(wgt::ShaderStage::FRAGMENT)),
+            (0, BindGroupLayoutEntryBuilder::new(BindingType::Sampler)
+                .visibility(wgt::ShaderStage::FRAGMENT)),
+        ];
+        assert!(matches!(
+            bind_entry_map(builders),
+            Err(BindGroupLayoutError::ConflictBinding(0))
+        ));
+    }
+
+    #[test]
+    fn bind_entry_map_collects_distinct_bindings() {
+        let builders = vec![
+            (0, BindGroupLayoutEntryBuilder::new(BindingType::Sampler)
+                .visibility(wgt::ShaderStage::FRAGMENT)),
+            (1, BindGroupLayoutEntryBuilder::new(BindingType::SampledTexture)
+                .visibility(wgt::ShaderStage::FRAGMENT)),
+        ];
+        assert_eq!(bind_entry_map(builders).unwrap().len(), 2);
+    }
+}